@@ -7,9 +7,20 @@
 // Thales Matheus Mendonça Santos - November 2025
 //
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use std::path::PathBuf;
 
+/// Output format for the generated report.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// The original human-formatted report with box-drawing headers.
+    Txt,
+    /// A single structured JSON document (project metadata, tree, and files).
+    Json,
+    /// Newline-delimited JSON, one object per file, streamed for low memory use.
+    Ndjson,
+}
+
 /// Command-line interface definition for the code scanner.
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -29,4 +40,43 @@ pub struct Args {
     /// Verbose mode
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Number of threads to use for walking and reading files (0 = auto-detect)
+    #[arg(long, default_value_t = 0)]
+    pub threads: usize,
+
+    /// Only scan files matching this registered type (repeatable, e.g. `--type rust`)
+    #[arg(long = "type", value_name = "TYPE")]
+    pub type_filters: Vec<String>,
+
+    /// Exclude files matching this registered type (repeatable, e.g. `--type-not test`)
+    #[arg(long = "type-not", value_name = "TYPE")]
+    pub type_not_filters: Vec<String>,
+
+    /// Add a glob to a type, creating it if needed (`name:glob`, repeatable)
+    #[arg(long = "type-add", value_name = "NAME:GLOB")]
+    pub type_add: Vec<String>,
+
+    /// Include/exclude glob override (repeatable, ripgrep semantics: `!pattern` excludes,
+    /// later patterns win over earlier ones). Takes precedence over .gitignore; files it
+    /// selects are still subject to the config's ignore lists and extension allowlist.
+    #[arg(short = 'g', long = "glob", value_name = "GLOB")]
+    pub globs: Vec<String>,
+
+    /// Don't walk up parent directories looking for their .gitignore rules
+    #[arg(long)]
+    pub no_ignore_parents: bool,
+
+    /// Don't honor .git/info/exclude
+    #[arg(long)]
+    pub no_git_exclude: bool,
+
+    /// Extra custom ignore filename to honor, like .gitignore (repeatable). `.scannerignore`
+    /// is always honored in addition to whatever is passed here.
+    #[arg(long = "ignore-file", value_name = "NAME")]
+    pub ignore_files: Vec<String>,
+
+    /// Report format: the original .txt layout, a single JSON document, or streamed NDJSON
+    #[arg(long, value_enum, default_value_t = OutputFormat::Txt)]
+    pub format: OutputFormat,
 }
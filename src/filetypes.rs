@@ -0,0 +1,97 @@
+//
+// filetypes.rs
+// Code-Scanner-rs
+//
+// Maintains the built-in file-type registry used by `--type`/`--type-not`
+// (ripgrep-style named selectors backed by glob patterns), and lets callers
+// extend it at runtime via `--type-add`.
+//
+// Thales Matheus Mendonça Santos - November 2025
+//
+
+use glob::Pattern;
+use std::collections::HashMap;
+
+// Built-in name -> glob patterns table. Kept small and easy to extend; users can
+// layer more globs onto any of these (or brand new names) with `--type-add`.
+const DEFAULT_TYPES: &[(&str, &[&str])] = &[
+    ("rust", &["*.rs"]),
+    (
+        "web",
+        &["*.js", "*.jsx", "*.ts", "*.tsx", "*.html", "*.css", "*.vue"],
+    ),
+    ("python", &["*.py", "*.pyi"]),
+    ("go", &["*.go"]),
+    ("java", &["*.java", "*.kt"]),
+    ("c", &["*.c", "*.h"]),
+    ("cpp", &["*.cpp", "*.hpp", "*.cc", "*.hh"]),
+    ("test", &["*_test.*", "*.test.*", "*.spec.*", "test_*.*"]),
+    ("config", &["*.json", "*.yaml", "*.yml", "*.toml"]),
+    ("docs", &["*.md", "*.rst", "*.txt"]),
+];
+
+// A compiled type registry mapping names to matchable glob patterns.
+#[derive(Debug, Clone)]
+pub struct TypeRegistry {
+    types: HashMap<String, Vec<Pattern>>,
+}
+
+impl Default for TypeRegistry {
+    fn default() -> Self {
+        let mut types = HashMap::new();
+        for (name, globs) in DEFAULT_TYPES {
+            types.insert((*name).to_string(), compile_globs(globs));
+        }
+        Self { types }
+    }
+}
+
+impl TypeRegistry {
+    // Parse and register every `name:glob` pair from `--type-add`, extending an
+    // existing type or creating a brand new one.
+    pub fn apply_type_add(&mut self, entries: &[String]) -> anyhow::Result<()> {
+        for entry in entries {
+            let (name, glob) = entry.split_once(':').ok_or_else(|| {
+                anyhow::anyhow!("--type-add '{}' must be in the form name:glob", entry)
+            })?;
+            self.add(name, glob)?;
+        }
+        Ok(())
+    }
+
+    // Register (or extend) a type with an additional glob pattern.
+    pub fn add(&mut self, name: &str, glob: &str) -> anyhow::Result<()> {
+        let pattern = Pattern::new(glob)
+            .with_context_glob(glob)?;
+        self.types.entry(name.to_string()).or_default().push(pattern);
+        Ok(())
+    }
+
+    // Whether `file_name` matches any pattern registered under `name`.
+    pub fn matches(&self, name: &str, file_name: &str) -> bool {
+        self.types
+            .get(name)
+            .map(|patterns| patterns.iter().any(|p| p.matches(file_name)))
+            .unwrap_or(false)
+    }
+
+    // Whether `file_name` matches at least one of the given type names.
+    pub fn matches_any(&self, names: &[String], file_name: &str) -> bool {
+        names.iter().any(|name| self.matches(name, file_name))
+    }
+}
+
+fn compile_globs(globs: &[&str]) -> Vec<Pattern> {
+    globs.iter().filter_map(|g| Pattern::new(g).ok()).collect()
+}
+
+// Small helper so `glob::PatternError` reads like the rest of our anyhow errors.
+trait WithContextGlob<T> {
+    fn with_context_glob(self, glob: &str) -> anyhow::Result<T>;
+}
+
+impl<T> WithContextGlob<T> for Result<T, glob::PatternError> {
+    fn with_context_glob(self, glob: &str) -> anyhow::Result<T> {
+        self.map_err(|e| anyhow::anyhow!("invalid glob '{}': {}", glob, e))
+    }
+}
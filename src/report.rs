@@ -0,0 +1,337 @@
+//
+// report.rs
+// Code-Scanner-rs
+//
+// Defines the ReportWriter abstraction used to emit a project's scan as either the
+// original human-readable .txt layout or a machine-readable JSON/NDJSON document,
+// and the concrete writers for each.
+//
+// Thales Matheus Mendonça Santos - November 2025
+//
+
+use crate::utils::format_size;
+use anyhow::Result;
+use chrono::Local;
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// The rendered result of reading a single file, shared by every writer.
+pub struct FileRecord {
+    pub relative_path: String,
+    pub size_bytes: u64,
+    pub is_binary: bool,
+    /// Text content, or `None` for binary/unreadable files.
+    pub content: Option<String>,
+}
+
+/// Incrementally emits a scan report as the scanner walks directories and reads files.
+/// Implementations decide how (or whether) to buffer state until `finish`.
+pub trait ReportWriter {
+    fn write_header(&mut self, project_name: &str, project_type: &str) -> Result<()>;
+    fn start_tree(&mut self) -> Result<()>;
+    fn write_tree_entry(&mut self, relative_path: &Path, depth: usize, is_dir: bool) -> Result<()>;
+    fn start_files(&mut self) -> Result<()>;
+    fn write_file(&mut self, record: &FileRecord) -> Result<()>;
+    fn finish(&mut self, processed_count: usize, skipped: u64, total_size: u64) -> Result<()>;
+}
+
+// --- TXT (default, human-readable) ---
+
+pub struct TxtReportWriter {
+    file: File,
+}
+
+impl TxtReportWriter {
+    pub fn new(file: File) -> Self {
+        Self { file }
+    }
+}
+
+impl ReportWriter for TxtReportWriter {
+    fn write_header(&mut self, project_name: &str, project_type: &str) -> Result<()> {
+        writeln!(
+            self.file,
+            "╔═══════════════════════════════════════════════════════════════╗"
+        )?;
+        writeln!(self.file, "║ PROJETO: {:<45}║", project_name)?;
+        writeln!(self.file, "║ Tipo: {:<48}║", project_type)?;
+        let now = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        writeln!(self.file, "║ Data: {:<48}║", now)?;
+        writeln!(
+            self.file,
+            "╚═══════════════════════════════════════════════════════════════╝"
+        )?;
+        Ok(())
+    }
+
+    fn start_tree(&mut self) -> Result<()> {
+        writeln!(self.file, "\n📂 ESTRUTURA DE DIRETÓRIOS")?;
+        writeln!(
+            self.file,
+            "═══════════════════════════════════════════════════════════════"
+        )?;
+        Ok(())
+    }
+
+    fn write_tree_entry(&mut self, relative_path: &Path, depth: usize, is_dir: bool) -> Result<()> {
+        let indent = "  ".repeat(depth.saturating_sub(1));
+        let name = relative_path.file_name().unwrap().to_string_lossy();
+        if is_dir {
+            writeln!(self.file, "{}├── {}/", indent, name)?;
+        } else {
+            writeln!(self.file, "{}└── {}", indent, name)?;
+        }
+        Ok(())
+    }
+
+    fn start_files(&mut self) -> Result<()> {
+        writeln!(self.file, "\n📄 CONTEÚDO DOS ARQUIVOS")?;
+        writeln!(
+            self.file,
+            "═══════════════════════════════════════════════════════════════"
+        )?;
+        Ok(())
+    }
+
+    fn write_file(&mut self, record: &FileRecord) -> Result<()> {
+        writeln!(
+            self.file,
+            "┌─────────────────────────────────────────────────────────────"
+        )?;
+        writeln!(self.file, "│ 📄 {}", record.relative_path)?;
+        writeln!(
+            self.file,
+            "│ 📊 Tamanho: {}",
+            format_size(record.size_bytes)
+        )?;
+        writeln!(
+            self.file,
+            "├─────────────────────────────────────────────────────────────"
+        )?;
+
+        match &record.content {
+            None if record.is_binary => {
+                writeln!(
+                    self.file,
+                    "│ [Binary file or unsupported encoding - content omitted]"
+                )?;
+            }
+            None => {
+                writeln!(self.file, "│ [Error reading file as UTF-8 text]")?;
+            }
+            Some(content) => {
+                for (i, line) in content.lines().enumerate() {
+                    writeln!(self.file, "{:>4} │ {}", i + 1, line)?;
+                }
+            }
+        }
+
+        writeln!(
+            self.file,
+            "└─────────────────────────────────────────────────────────────\n"
+        )?;
+        Ok(())
+    }
+
+    fn finish(&mut self, processed_count: usize, skipped: u64, total_size: u64) -> Result<()> {
+        writeln!(
+            self.file,
+            "\n═══════════════════════════════════════════════════════════════"
+        )?;
+        writeln!(self.file, "📊 RESUMO")?;
+        writeln!(self.file, "  ✅ Arquivos processados: {}", processed_count)?;
+        writeln!(self.file, "  ⏭️  Arquivos ignorados (estimado): {}", skipped)?;
+        writeln!(
+            self.file,
+            "  💾 Tamanho total do conteúdo: {}",
+            format_size(total_size)
+        )?;
+        writeln!(
+            self.file,
+            "═══════════════════════════════════════════════════════════════"
+        )?;
+        Ok(())
+    }
+}
+
+// --- JSON (single buffered document) ---
+
+#[derive(Serialize)]
+struct TreeEntryOut {
+    relative_path: String,
+    is_dir: bool,
+}
+
+#[derive(Serialize)]
+struct FileRecordOut {
+    relative_path: String,
+    size_bytes: u64,
+    is_binary: bool,
+    content: Option<String>,
+}
+
+#[derive(Serialize, Default)]
+struct SummaryOut {
+    processed_files: usize,
+    skipped_files: u64,
+    total_size_bytes: u64,
+}
+
+#[derive(Serialize, Default)]
+struct JsonDocument {
+    project_name: String,
+    project_type: String,
+    generated_at: String,
+    tree: Vec<TreeEntryOut>,
+    files: Vec<FileRecordOut>,
+    summary: SummaryOut,
+}
+
+pub struct JsonReportWriter {
+    file: File,
+    doc: JsonDocument,
+}
+
+impl JsonReportWriter {
+    pub fn new(file: File) -> Self {
+        Self {
+            file,
+            doc: JsonDocument::default(),
+        }
+    }
+}
+
+impl ReportWriter for JsonReportWriter {
+    fn write_header(&mut self, project_name: &str, project_type: &str) -> Result<()> {
+        self.doc.project_name = project_name.to_string();
+        self.doc.project_type = project_type.to_string();
+        self.doc.generated_at = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        Ok(())
+    }
+
+    fn start_tree(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_tree_entry(&mut self, relative_path: &Path, _depth: usize, is_dir: bool) -> Result<()> {
+        self.doc.tree.push(TreeEntryOut {
+            relative_path: relative_path.to_string_lossy().into_owned(),
+            is_dir,
+        });
+        Ok(())
+    }
+
+    fn start_files(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_file(&mut self, record: &FileRecord) -> Result<()> {
+        self.doc.files.push(FileRecordOut {
+            relative_path: record.relative_path.clone(),
+            size_bytes: record.size_bytes,
+            is_binary: record.is_binary,
+            content: record.content.clone(),
+        });
+        Ok(())
+    }
+
+    fn finish(&mut self, processed_count: usize, skipped: u64, total_size: u64) -> Result<()> {
+        self.doc.summary = SummaryOut {
+            processed_files: processed_count,
+            skipped_files: skipped,
+            total_size_bytes: total_size,
+        };
+        serde_json::to_writer_pretty(&mut self.file, &self.doc)?;
+        writeln!(self.file)?;
+        Ok(())
+    }
+}
+
+// --- NDJSON (streamed, one object per line) ---
+
+pub struct NdjsonReportWriter {
+    file: File,
+}
+
+impl NdjsonReportWriter {
+    pub fn new(file: File) -> Self {
+        Self { file }
+    }
+
+    fn write_line(&mut self, value: &impl Serialize) -> Result<()> {
+        serde_json::to_writer(&mut self.file, value)?;
+        writeln!(self.file)?;
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum NdjsonLine<'a> {
+    Meta {
+        project_name: &'a str,
+        project_type: &'a str,
+        generated_at: String,
+    },
+    Tree {
+        relative_path: String,
+        is_dir: bool,
+    },
+    File {
+        relative_path: &'a str,
+        size_bytes: u64,
+        is_binary: bool,
+        content: &'a Option<String>,
+    },
+    Summary {
+        processed_files: usize,
+        skipped_files: u64,
+        total_size_bytes: u64,
+    },
+}
+
+impl ReportWriter for NdjsonReportWriter {
+    fn write_header(&mut self, project_name: &str, project_type: &str) -> Result<()> {
+        self.write_line(&NdjsonLine::Meta {
+            project_name,
+            project_type,
+            generated_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        })
+    }
+
+    fn start_tree(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_tree_entry(&mut self, relative_path: &Path, _depth: usize, is_dir: bool) -> Result<()> {
+        self.write_line(&NdjsonLine::Tree {
+            relative_path: relative_path.to_string_lossy().into_owned(),
+            is_dir,
+        })
+    }
+
+    fn start_files(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_file(&mut self, record: &FileRecord) -> Result<()> {
+        // Streamed one object per file so huge projects never need to hold
+        // every file's content in memory at once.
+        self.write_line(&NdjsonLine::File {
+            relative_path: &record.relative_path,
+            size_bytes: record.size_bytes,
+            is_binary: record.is_binary,
+            content: &record.content,
+        })
+    }
+
+    fn finish(&mut self, processed_count: usize, skipped: u64, total_size: u64) -> Result<()> {
+        self.write_line(&NdjsonLine::Summary {
+            processed_files: processed_count,
+            skipped_files: skipped,
+            total_size_bytes: total_size,
+        })
+    }
+}
@@ -7,17 +7,19 @@
 // Thales Matheus Mendonça Santos - November 2025
 //
 
-use crate::cli::Args;
+use crate::cli::{Args, OutputFormat};
 use crate::config::{load_config, ProjectConfig};
+use crate::filetypes::TypeRegistry;
 use crate::project::detect_project_type;
-use crate::utils::{format_size, is_binary};
+use crate::report::{FileRecord, JsonReportWriter, NdjsonReportWriter, ReportWriter, TxtReportWriter};
+use crate::utils::is_binary;
 use anyhow::{Context, Result};
-use chrono::Local;
-use ignore::{Walk, WalkBuilder};
+use ignore::overrides::OverrideBuilder;
+use ignore::{WalkBuilder, WalkState};
 use pathdiff::diff_paths;
 use std::fs::{self, File};
-use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 // Orchestrates a full scan for a single project and writes the report.
 pub fn process_project(project_path: &Path, output_dir: &Path, args: &Args) -> Result<()> {
@@ -27,263 +29,384 @@ pub fn process_project(project_path: &Path, output_dir: &Path, args: &Args) -> R
         .to_string_lossy()
         .into_owned();
 
-    let output_file_path = output_dir.join(format!("{}_project_code.txt", project_name));
+    let output_file_path = output_dir.join(format!(
+        "{}_project_code.{}",
+        project_name,
+        output_extension(args.format)
+    ));
     let project_type = detect_project_type(project_path);
     let config = load_config(project_path);
 
     // Visible progress helps when scanning multiple folders.
     println!("📦 Processando: {} ({})", project_name, project_type);
 
-    let mut output_file = File::create(&output_file_path).with_context(|| {
+    let output_file = File::create(&output_file_path).with_context(|| {
         format!(
             "Falha ao criar arquivo de saída: {}",
             output_file_path.display()
         )
     })?;
+    let mut writer = build_writer(args.format, output_file);
 
-    write_header(&mut output_file, &project_name, &project_type)?;
-    writeln!(output_file, "\n📂 ESTRUTURA DE DIRETÓRIOS")?;
-    writeln!(
-        output_file,
-        "═══════════════════════════════════════════════════════════════"
-    )?;
+    writer.write_header(&project_name, &project_type)?;
+    writer.start_tree()?;
 
-    // Walk the file system with the configured filters and collect files to dump.
-    let walker = build_walker(project_path, args);
+    let mut type_registry = TypeRegistry::default();
+    type_registry.apply_type_add(&args.type_add)?;
+
+    // Walk the file system in parallel, then sort so the report stays
+    // reproducible regardless of how the walker threads were scheduled.
+    let entries = collect_entries(project_path, build_walker(project_path, args, &config)?);
     let (mut valid_files, mut stats) =
-        collect_files(project_path, &config, args, walker, &mut output_file)?;
+        filter_entries(&config, args, &type_registry, &entries, writer.as_mut())?;
     valid_files.sort();
 
-    writeln!(output_file, "\n📄 CONTEÚDO DOS ARQUIVOS")?;
-    writeln!(
-        output_file,
-        "═══════════════════════════════════════════════════════════════"
-    )?;
+    writer.start_files()?;
 
-    write_file_contents(project_path, &valid_files, &mut output_file, &mut stats)?;
-    write_summary(&mut output_file, &stats, valid_files.len())?;
+    write_file_contents(project_path, &valid_files, writer.as_mut(), &mut stats, args.threads)?;
+    writer.finish(valid_files.len(), stats.skipped, stats.total_size)?;
 
     println!("  ✅ Salvo em: {}", output_file_path.display());
     Ok(())
 }
 
-fn build_walker(project_path: &Path, args: &Args) -> Walk {
+fn output_extension(format: OutputFormat) -> &'static str {
+    match format {
+        OutputFormat::Txt => "txt",
+        OutputFormat::Json => "json",
+        OutputFormat::Ndjson => "ndjson",
+    }
+}
+
+fn build_writer(format: OutputFormat, file: File) -> Box<dyn ReportWriter> {
+    match format {
+        OutputFormat::Txt => Box::new(TxtReportWriter::new(file)),
+        OutputFormat::Json => Box::new(JsonReportWriter::new(file)),
+        OutputFormat::Ndjson => Box::new(NdjsonReportWriter::new(file)),
+    }
+}
+
+// Always honored alongside any user-supplied `--ignore-file` names.
+const DEFAULT_IGNORE_FILE: &str = ".scannerignore";
+
+fn build_walker(project_path: &Path, args: &Args, config: &ProjectConfig) -> Result<WalkBuilder> {
     // Build a walker that respects .gitignore unless the user disabled it.
-    WalkBuilder::new(project_path)
+    // `threads(0)` lets the `ignore` crate pick a sensible default itself.
+    let mut builder = WalkBuilder::new(project_path);
+    builder
         .git_ignore(!args.no_gitignore)
+        .git_exclude(!args.no_git_exclude)
+        .parents(!args.no_ignore_parents)
         .hidden(false)
-        .build()
+        .threads(args.threads);
+
+    // Prune `ignore_dirs` at the walker level so the scan never descends into
+    // (and the parallel workers never visit) directories like `node_modules`.
+    let ignore_dirs = config.ignore_dirs.clone();
+    builder.filter_entry(move |entry| {
+        let file_name = entry.file_name().to_string_lossy().to_lowercase();
+        !ignore_dirs.contains(&file_name)
+    });
+
+    // `.scannerignore` is always honored in addition to any `--ignore-file` names;
+    // a default_value on a repeatable flag would be replaced by user input, which
+    // would silently turn off `.scannerignore` support the moment someone passes
+    // their own `--ignore-file`.
+    builder.add_custom_ignore_filename(DEFAULT_IGNORE_FILE);
+    for name in &args.ignore_files {
+        builder.add_custom_ignore_filename(name);
+    }
+
+    if args.verbose {
+        log_ignore_sources(project_path, args);
+    }
+
+    if !args.globs.is_empty() {
+        // `--glob`/`-g` follow ripgrep override semantics: `!pattern` excludes,
+        // later patterns win over earlier ones, and these take precedence over
+        // .gitignore. They do not override the config: `ignore_dirs` pruning and
+        // the checks in `filter_entries` still drop files a pattern selects.
+        let mut overrides = OverrideBuilder::new(project_path);
+        for pattern in &args.globs {
+            overrides
+                .add(pattern)
+                .with_context(|| format!("--glob inválido: {}", pattern))?;
+        }
+        builder.overrides(overrides.build().context("falha ao compilar padrões --glob")?);
+    }
+
+    Ok(builder)
 }
 
-fn write_header(output_file: &mut File, project_name: &str, project_type: &str) -> Result<()> {
-    // Simple header for the human-friendly report.
-    writeln!(
-        output_file,
-        "╔═══════════════════════════════════════════════════════════════╗"
-    )?;
-    writeln!(output_file, "║ PROJETO: {:<45}║", project_name)?;
-    writeln!(output_file, "║ Tipo: {:<48}║", project_type)?;
-    let now = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-    writeln!(output_file, "║ Data: {:<48}║", now)?;
-    writeln!(
-        output_file,
-        "╚═══════════════════════════════════════════════════════════════╝"
-    )?;
-    Ok(())
+// Print which ignore sources the walker will honor, so users can debug why a
+// file was unexpectedly skipped. This mirrors `build_walker`'s configuration
+// rather than introspecting the walker itself, since `ignore` doesn't expose
+// the set of files it actually loaded. It lists `.gitignore`, `.git/info/exclude`
+// and the custom ignore files; `.ignore` files and the global git excludes file
+// are not listed.
+fn log_ignore_sources(project_path: &Path, args: &Args) {
+    let mut dirs = vec![project_path.to_path_buf()];
+    if !args.no_ignore_parents {
+        // The walker resolves parents from the canonical path, so a relative
+        // input still reaches directories above the working directory.
+        let base = project_path
+            .canonicalize()
+            .unwrap_or_else(|_| project_path.to_path_buf());
+        dirs.extend(base.ancestors().skip(1).map(Path::to_path_buf));
+    }
+
+    for dir in &dirs {
+        if !args.no_gitignore {
+            let gitignore = dir.join(".gitignore");
+            if gitignore.is_file() {
+                println!("🔍 Ignore file carregado: {}", gitignore.display());
+            }
+        }
+        if !args.no_git_exclude {
+            let exclude = dir.join(".git").join("info").join("exclude");
+            if exclude.is_file() {
+                println!("🔍 Ignore file carregado: {}", exclude.display());
+            }
+        }
+        for name in std::iter::once(DEFAULT_IGNORE_FILE).chain(args.ignore_files.iter().map(String::as_str)) {
+            let custom = dir.join(name);
+            if custom.is_file() {
+                println!("🔍 Ignore file carregado: {}", custom.display());
+            }
+        }
+    }
 }
 
-fn collect_files(
-    project_path: &Path,
-    config: &ProjectConfig,
-    args: &Args,
-    walker: Walk,
-    output_file: &mut File,
-) -> Result<(Vec<PathBuf>, ScanStats)> {
-    let mut valid_files: Vec<PathBuf> = Vec::new();
-    let mut stats = ScanStats::default();
+// A single walked entry, kept minimal so the parallel collection pass stays cheap.
+#[derive(Debug)]
+struct WalkEntry {
+    path: PathBuf,
+    relative_path: PathBuf,
+    is_dir: bool,
+}
 
-    for result in walker {
-        match result {
-            // ignore::Walk yields entries that can error; handle them gently.
-            Ok(entry) => {
+// Drive the walker with `build_parallel`, funnelling every visited entry into a
+// shared vector. The workers fill it in whatever order thread scheduling
+// produces, so it is sorted by relative path before being returned.
+fn collect_entries(project_path: &Path, builder: WalkBuilder) -> Vec<WalkEntry> {
+    let entries: Arc<Mutex<Vec<WalkEntry>>> = Arc::new(Mutex::new(Vec::new()));
+
+    builder.build_parallel().run(|| {
+        let entries = Arc::clone(&entries);
+        let project_path = project_path.to_path_buf();
+        Box::new(move |result| {
+            if let Ok(entry) = result {
                 let path = entry.path();
-
                 if path == project_path {
                     // Skip the root path itself; we only care about its children.
-                    continue;
+                    return WalkState::Continue;
                 }
 
                 let relative_path =
-                    diff_paths(path, project_path).unwrap_or_else(|| path.to_path_buf());
-                let file_name = path
-                    .file_name()
-                    .unwrap_or_default()
-                    .to_string_lossy()
-                    .to_lowercase();
-
-                if config.ignore_dirs.contains(&file_name) {
-                    // Prune entire directories early to avoid unnecessary work.
-                    continue;
-                }
+                    diff_paths(path, &project_path).unwrap_or_else(|| path.to_path_buf());
+                let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+
+                entries.lock().unwrap().push(WalkEntry {
+                    path: path.to_path_buf(),
+                    relative_path,
+                    is_dir,
+                });
+            }
+            WalkState::Continue
+        })
+    });
+
+    // `run` blocks until every worker thread has finished, so this is the only
+    // remaining reference to `entries`.
+    let mut entries = Arc::try_unwrap(entries).unwrap().into_inner().unwrap();
+    // Deterministic order regardless of thread scheduling, so the tree section
+    // renders the same way on every run.
+    entries.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    entries
+}
 
-                if path.is_dir() {
-                    // Log directory structure in the output file with indentation.
-                    let depth = relative_path.components().count();
-                    let indent = "  ".repeat(depth.saturating_sub(1));
-                    writeln!(
-                        output_file,
-                        "{}├── {}/",
-                        indent,
-                        relative_path.file_name().unwrap().to_string_lossy()
-                    )?;
-                    continue;
-                }
+fn filter_entries(
+    config: &ProjectConfig,
+    args: &Args,
+    type_registry: &TypeRegistry,
+    entries: &[WalkEntry],
+    writer: &mut dyn ReportWriter,
+) -> Result<(Vec<PathBuf>, ScanStats)> {
+    let mut valid_files: Vec<PathBuf> = Vec::new();
+    let mut stats = ScanStats::default();
 
-                if config.ignore_files.contains(&file_name) {
-                    // Skip noisy files but still count them as skipped for the summary.
-                    stats.skipped += 1;
-                    continue;
-                }
+    for entry in entries {
+        let file_name = entry
+            .path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_lowercase();
+
+        if config.ignore_dirs.contains(&file_name) {
+            // The walker's `filter_entry` (see `build_walker`) already prunes these
+            // directories before they're ever visited; this is just a defensive
+            // backstop in case an entry slips through some other way.
+            continue;
+        }
 
-                let ext = path
-                    .extension()
-                    .map(|e| e.to_string_lossy().to_string().to_lowercase())
-                    .unwrap_or_default();
+        if entry.is_dir {
+            let depth = entry.relative_path.components().count();
+            writer.write_tree_entry(&entry.relative_path, depth, true)?;
+            continue;
+        }
 
-                if config.ignore_extensions.contains(&ext) {
-                    // Common binary or heavy files we do not want to dump.
-                    stats.skipped += 1;
-                    continue;
-                }
+        if config.ignore_files.contains(&file_name) {
+            // Skip noisy files but still count them as skipped for the summary.
+            stats.skipped += 1;
+            continue;
+        }
 
-                // If an extension exists and is not whitelisted, drop it unless the
-                // whole filename is explicitly whitelisted (Dockerfile, Makefile, etc).
-                if !ext.is_empty() && !config.code_extensions.contains(&ext) {
-                    if !config.code_extensions.contains(&file_name) {
-                        stats.skipped += 1;
-                        continue;
-                    }
-                }
+        let ext = entry
+            .path
+            .extension()
+            .map(|e| e.to_string_lossy().to_string().to_lowercase())
+            .unwrap_or_default();
 
-                let metadata = match path.metadata() {
-                    Ok(m) => m,
-                    Err(_) => continue,
-                };
-
-                // Enforce max file size to keep output manageable.
-                if metadata.len() > config.max_file_size {
-                    if args.verbose {
-                        println!("Ignorando {} (tamanho excessivo)", relative_path.display());
-                    }
-                    stats.skipped += 1;
-                    continue;
-                }
+        if config.ignore_extensions.contains(&ext) {
+            // Common binary or heavy files we do not want to dump.
+            stats.skipped += 1;
+            continue;
+        }
 
-                valid_files.push(path.to_path_buf());
-
-                // Record the file in the tree view with indentation to reflect depth.
-                let depth = relative_path.components().count();
-                let indent = "  ".repeat(depth.saturating_sub(1));
-                writeln!(
-                    output_file,
-                    "{}└── {}",
-                    indent,
-                    relative_path.file_name().unwrap().to_string_lossy()
-                )?;
+        // `--type-not` always excludes, regardless of `--type`.
+        if type_registry.matches_any(&args.type_not_filters, &file_name) {
+            stats.skipped += 1;
+            continue;
+        }
+
+        if !args.type_filters.is_empty() {
+            // Named `--type` selectors take over inclusion entirely; the
+            // extension allowlist below is only a fallback when none are given.
+            if !type_registry.matches_any(&args.type_filters, &file_name) {
+                stats.skipped += 1;
+                continue;
             }
-            Err(err) => {
-                if args.verbose {
-                    eprintln!("Erro ao ler entrada: {}", err);
-                }
+        } else if !ext.is_empty() && !config.code_extensions.contains(&ext) {
+            // If an extension exists and is not whitelisted, drop it unless the
+            // whole filename is explicitly whitelisted (Dockerfile, Makefile, etc).
+            if !config.code_extensions.contains(&file_name) {
+                stats.skipped += 1;
+                continue;
+            }
+        }
+
+        let metadata = match entry.path.metadata() {
+            Ok(m) => m,
+            Err(_) => continue,
+        };
+
+        // Enforce max file size to keep output manageable.
+        if metadata.len() > config.max_file_size {
+            if args.verbose {
+                println!(
+                    "Ignorando {} (tamanho excessivo)",
+                    entry.relative_path.display()
+                );
             }
+            stats.skipped += 1;
+            continue;
         }
+
+        valid_files.push(entry.path.clone());
+
+        let depth = entry.relative_path.components().count();
+        writer.write_tree_entry(&entry.relative_path, depth, false)?;
     }
 
     Ok((valid_files, stats))
 }
 
+fn render_file(project_path: &Path, path: &Path) -> FileRecord {
+    let relative_path = diff_paths(path, project_path).unwrap_or_else(|| path.to_path_buf());
+    let size_bytes = path.metadata().map(|m| m.len()).unwrap_or(0);
+
+    // Avoid dumping binary content which would clutter the report.
+    let (is_binary_file, content) = if is_binary(path) {
+        (true, None)
+    } else {
+        (false, fs::read_to_string(path).ok())
+    };
+
+    FileRecord {
+        relative_path: relative_path.to_string_lossy().into_owned(),
+        size_bytes,
+        is_binary: is_binary_file,
+        content,
+    }
+}
+
+fn resolve_worker_count(threads: usize, total_files: usize) -> usize {
+    let count = if threads == 0 {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    } else {
+        threads
+    };
+    count.min(total_files.max(1))
+}
+
+// Read one batch of files across up to `worker_count` threads, returning the
+// records in the same order as `batch` so the report stays stable regardless
+// of scheduling.
+fn render_batch(project_path: &Path, batch: &[PathBuf], worker_count: usize) -> Vec<FileRecord> {
+    if worker_count <= 1 || batch.len() <= 1 {
+        return batch.iter().map(|p| render_file(project_path, p)).collect();
+    }
+
+    let chunk_size = batch.len().div_ceil(worker_count);
+    let mut rendered = Vec::with_capacity(batch.len());
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = batch
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|p| render_file(project_path, p))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            rendered.extend(handle.join().expect("render worker panicked"));
+        }
+    });
+
+    rendered
+}
+
 fn write_file_contents(
     project_path: &Path,
     files: &[PathBuf],
-    output_file: &mut File,
+    writer: &mut dyn ReportWriter,
     stats: &mut ScanStats,
+    threads: usize,
 ) -> Result<()> {
-    for path in files {
-        let relative_path = diff_paths(path, project_path).unwrap_or_else(|| path.to_path_buf());
-        let relative_str = relative_path.to_string_lossy();
-        let size = path
-            .metadata()
-            .with_context(|| format!("Falha ao ler metadata de {}", relative_path.display()))?
-            .len();
-
-        // Section header for each individual file.
-        writeln!(
-            output_file,
-            "┌─────────────────────────────────────────────────────────────"
-        )?;
-        writeln!(output_file, "│ 📄 {}", relative_str)?;
-        writeln!(output_file, "│ 📊 Tamanho: {}", format_size(size))?;
-        writeln!(
-            output_file,
-            "├─────────────────────────────────────────────────────────────"
-        )?;
-
-        // Avoid dumping binary content which would clutter the report.
-        if is_binary(path) {
-            writeln!(
-                output_file,
-                "│ [Binary file or unsupported encoding - content omitted]"
-            )?;
-        } else {
-            match fs::read_to_string(path) {
-                Ok(content) => {
-                    // Include line numbers to make the output easy to reference.
-                    for (i, line) in content.lines().enumerate() {
-                        writeln!(output_file, "{:>4} │ {}", i + 1, line)?;
-                    }
-                }
-                Err(_) => {
-                    writeln!(output_file, "│ [Error reading file as UTF-8 text]")?;
-                }
-            }
-        }
-
-        writeln!(
-            output_file,
-            "└─────────────────────────────────────────────────────────────\n"
-        )?;
-        stats.total_size += size;
+    if files.is_empty() {
+        return Ok(());
     }
 
-    Ok(())
-}
+    let worker_count = resolve_worker_count(threads, files.len());
+
+    // Render and flush one worker-sized batch at a time: this keeps reading
+    // parallel while bounding how much file content is held in memory at
+    // once to a single batch, so NDJSON (and any other writer) can stream.
+    for batch in files.chunks(worker_count) {
+        for record in render_batch(project_path, batch, worker_count) {
+            stats.total_size += record.size_bytes;
+            writer.write_file(&record)?;
+        }
+    }
 
-fn write_summary(output_file: &mut File, stats: &ScanStats, processed_count: usize) -> Result<()> {
-    // Final footer with a lightweight count of what happened.
-    writeln!(
-        output_file,
-        "\n═══════════════════════════════════════════════════════════════"
-    )?;
-    writeln!(output_file, "📊 RESUMO")?;
-    writeln!(
-        output_file,
-        "  ✅ Arquivos processados: {}",
-        processed_count
-    )?;
-    writeln!(
-        output_file,
-        "  ⏭️  Arquivos ignorados (estimado): {}",
-        stats.skipped
-    )?;
-    writeln!(
-        output_file,
-        "  💾 Tamanho total do conteúdo: {}",
-        format_size(stats.total_size)
-    )?;
-    writeln!(
-        output_file,
-        "═══════════════════════════════════════════════════════════════"
-    )?;
     Ok(())
 }
 